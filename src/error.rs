@@ -33,6 +33,37 @@ pub enum ParsingError {
         expression: String,
         modulo: String,
     },
+
+    /// a month/week-day name, or an `@`-nickname, is not recognized
+    InvalidName {
+        expression: String,
+        name: String,
+    },
+
+    /// a `Schedule::parse_human` phrase contains a token that doesn't fit the supported grammar
+    InvalidPhrase {
+        input: String,
+        token: String,
+    },
+
+    /// a required field is missing from the expression (e.g. an RRULE without `FREQ`)
+    MissingField {
+        expression: String,
+        field: String,
+    },
+
+    /// an `UNTIL` value isn't a valid `YYYYMMDDTHHMMSSZ` date/time
+    InvalidUntil {
+        expression: String,
+        until: String,
+    },
+
+    /// a `BYDAY` entry has a leading ordinal (`1MO`, `-1FR`, ...), which this crate doesn't
+    /// support : it is rejected rather than silently stripped down to the plain weekday
+    UnsupportedByDayOrdinal {
+        expression: String,
+        token: String,
+    },
 }
 
 impl Display for ParsingError {
@@ -53,8 +84,46 @@ impl Display for ParsingError {
             ParsingError::InvalidModulo { expression, modulo } => {
                 write!(f, r#"The expression "{expression}" is invalid : the modulo {modulo} is not a correct modulo."#)
             },
+            ParsingError::InvalidName { expression, name } => {
+                write!(f, r#"The expression "{expression}" is invalid : the name "{name}" is not recognized."#)
+            },
+            ParsingError::InvalidPhrase { input, token } => {
+                write!(f, r#"The phrase "{input}" is invalid : the token "{token}" is not recognized."#)
+            },
+            ParsingError::MissingField { expression, field } => {
+                write!(f, r#"The expression "{expression}" is invalid : the required field "{field}" is missing."#)
+            },
+            ParsingError::InvalidUntil { expression, until } => {
+                write!(f, r#"The expression "{expression}" is invalid : "{until}" is not a valid UNTIL date/time."#)
+            },
+            ParsingError::UnsupportedByDayOrdinal { expression, token } => {
+                write!(f, r#"The expression "{expression}" is invalid : the BYDAY ordinal in "{token}" is not supported."#)
+            },
         }
     }
 }
 
 impl Error for ParsingError {}
+
+/// Error raised while stepping through time (e.g. in `TimeExtension` or `Schedule::get_next_match`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeError {
+    /// a date/time computation (adding a duration, or replacing a field) overflowed the
+    /// representable range
+    Overflow,
+
+    /// the search for a matching date didn't converge within the bounded horizon, meaning the
+    /// schedule is effectively unreachable (e.g. `0 0 30 2 *`, which asks for February 30th)
+    NoMatchWithinHorizon,
+}
+
+impl Display for TimeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimeError::Overflow => write!(f, "the date/time computation overflowed the representable range"),
+            TimeError::NoMatchWithinHorizon => write!(f, "no matching date was found within the search horizon ; the schedule is likely unreachable"),
+        }
+    }
+}
+
+impl Error for TimeError {}
@@ -5,17 +5,22 @@
 //! - `previous_occurrence` : returns the previous occurrence of the schedule before a given date
 //! - `matches` : returns true if the schedule matches a given date
 //! and many more.
-//! 
+//!
+//! Both `Schedule` (cron syntax) and `RecurrenceRule` (RRULE syntax) implement the shared
+//! `Recurring` trait, so calling code can ask either kind of schedule for its next match without
+//! caring which one it is.
+//!
 //! ## How to use it
 //! 
 //! ## How the project is structured
 //! ### lib.rs
 //! ### time_extensions.rs
 //! ### error.rs
+//! ### recurrence.rs
 //! ### test.rs
 
 // use the time crate for timekeeping
-use time::OffsetDateTime;
+use time::{OffsetDateTime, UtcOffset};
 
 // extend the OffsetDateTime struct with useful methods
 mod time_extension;
@@ -23,22 +28,70 @@ use time_extension::TimeExtension;
 
 // load custom error types
 mod error;
-use error::{ParsingError};
+use error::{ParsingError, TimeError};
+
+// RFC 5545 RRULE recurrence rules, alongside the cron-style `Schedule` below
+mod recurrence;
+pub use recurrence::{Frequency, RecurrenceRule};
+
+/// Resolves the UTC offset that applies to civil (wall-clock) time at a given instant.
+///
+/// Implement this against a real IANA time-zone database to get full daylight-saving support ;
+/// `UtcOffset` itself implements it trivially, for schedules pinned to a single fixed offset.
+pub trait TimeZone {
+    /// returns the UTC offset in effect at the given UTC instant
+    fn offset_at(&self, utc: OffsetDateTime) -> UtcOffset;
+}
 
-/// A schedule is composed of 4 fields:
+/// a fixed offset is its own (trivial, DST-less) time zone
+impl TimeZone for UtcOffset {
+    fn offset_at(&self, _utc: OffsetDateTime) -> UtcOffset {
+        *self
+    }
+}
+
+/// a common interface shared by `Schedule` (cron syntax) and `RecurrenceRule` (RRULE syntax), so
+/// calling code can hold either kind of schedule and ask for "the next match" without caring
+/// which one it is.
+pub trait Recurring {
+    /// the first occurrence strictly after `after`, or `None` if the schedule has no further
+    /// occurrences (e.g. a `RecurrenceRule` that has exhausted its `COUNT`/`UNTIL`)
+    fn next_match(&self, after: OffsetDateTime) -> Result<Option<OffsetDateTime>, TimeError>;
+}
+
+impl Recurring for Schedule {
+    fn next_match(&self, after: OffsetDateTime) -> Result<Option<OffsetDateTime>, TimeError> {
+        match self.get_next_match(after) {
+            Ok(date) => Ok(Some(date)),
+            Err(TimeError::NoMatchWithinHorizon) => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+/// A schedule is composed of 5 fields:
 /// - `months`: a list of months (1-12)
-/// - `days`: a list of days (1-31)
+/// - `days_of_month`: a list of days (1-31)
+/// - `days_of_week`: a list of week-days (0-6, Sunday to Saturday)
 /// - `hours`: a list of hours (0-23)
 /// - `minutes`: a list of minutes (0-59)
-/// 
+///
 /// Each field is a bitfield, where each bit represents a month/day/hour/minute.
 /// The order is the following : ... 4 3 2 1 0 (example for minutes). This means that increasing the value of the field will increase the time. This technique allows to encode a schedule in a small enough space.
+///
+/// `days_of_month_is_wildcard` and `days_of_week_is_wildcard` remember whether those two fields
+/// were left as `*` in the original expression, which is needed to apply the Vixie-cron rule for
+/// combining them (see `is_day_valid`).
 #[derive(Debug, Clone, Copy)]
 pub struct Schedule {
     months: u64,        // 1-12
     days_of_month: u64, // 1-31
+    days_of_week: u64,  // 0-6 (Sunday-Saturday)
     hours: u64,         // 0-23
     minutes: u64,       // 0-59
+
+    days_of_month_is_wildcard: bool,
+    days_of_week_is_wildcard: bool,
 }
 
 impl Schedule {
@@ -50,15 +103,39 @@ impl Schedule {
     /// # Examples
     /// ```
     /// use scheduler::Schedule;
-    /// 
+    /// use time::macros::datetime;
+    ///
     /// fn main() {
     ///     let schedule = Schedule::new("0 * * * *").unwrap();
-    ///     assert_eq!("", schedule.get_next_match());
+    ///     let next = schedule.get_next_match(datetime!(2024-01-01 0:00 UTC)).unwrap();
+    ///     assert_eq!(next, datetime!(2024-01-01 1:00 UTC));
     /// }
     /// ```
     pub fn new(expression: &str) -> Result<Self, ParsingError> {
         // todo : verify that the expression is valid
 
+        // fast path : recognize @-nicknames and expand them to their canonical 5-field form
+        // before doing any further parsing
+        let expanded;
+        let expression: &str = if let Some(nickname) = expression.strip_prefix('@') {
+            expanded = match nickname.to_ascii_lowercase().as_str() {
+                "yearly" | "annually" => "0 0 1 1 *",
+                "monthly" => "0 0 1 * *",
+                "weekly" => "0 0 * * 0",
+                "daily" => "0 0 * * *",
+                "hourly" => "0 * * * *",
+                _ => {
+                    return Err(ParsingError::InvalidName {
+                        expression: expression.to_owned(),
+                        name: expression.to_owned(),
+                    });
+                }
+            };
+            expanded
+        } else {
+            expression
+        };
+
         // split the expression into 5 components (minutes, hours, month-days, months, and week-days)
         let component: Vec<&str> = expression.split(" ").collect();
 
@@ -71,9 +148,126 @@ impl Schedule {
             });
         }
 
+        // maps a month (JAN-DEC) or week-day (SUN-SAT) name, case-insensitively, to the bit
+        // index a number in the same position would have used ; returns `None` for fields that
+        // don't have names (neither `MIN..=MAX` is 1..=12 nor 0..=6) or for a name that isn't
+        // recognized
+        #[inline(always)]
+        fn name_to_index<const MIN: usize, const MAX: usize>(name: &str) -> Option<usize> {
+            let name = name.to_ascii_uppercase();
+            if MIN == 1 && MAX == 12 {
+                match name.as_str() {
+                    "JAN" => Some(1),
+                    "FEB" => Some(2),
+                    "MAR" => Some(3),
+                    "APR" => Some(4),
+                    "MAY" => Some(5),
+                    "JUN" => Some(6),
+                    "JUL" => Some(7),
+                    "AUG" => Some(8),
+                    "SEP" => Some(9),
+                    "OCT" => Some(10),
+                    "NOV" => Some(11),
+                    "DEC" => Some(12),
+                    _ => None,
+                }
+            } else if MIN == 0 && MAX == 6 {
+                match name.as_str() {
+                    "SUN" => Some(0),
+                    "MON" => Some(1),
+                    "TUE" => Some(2),
+                    "WED" => Some(3),
+                    "THU" => Some(4),
+                    "FRI" => Some(5),
+                    "SAT" => Some(6),
+                    _ => None,
+                }
+            } else {
+                None
+            }
+        }
+
+        // parses a single number or (for months/week-days) a name into a bit index
+        #[inline(always)]
+        fn parse_index<const MIN: usize, const MAX: usize>(expression: &str, token: &str) -> Result<usize, ParsingError> {
+            if let Ok(n) = token.parse() {
+                return Ok(n);
+            }
+
+            if let Some(n) = name_to_index::<MIN, MAX>(token) {
+                return Ok(n);
+            }
+
+            // if the field supports names but the token isn't a valid one, report it as such
+            // instead of the more generic "invalid number"
+            if (MIN, MAX) == (1, 12) || (MIN, MAX) == (0, 6) {
+                Err(ParsingError::InvalidName {
+                    expression: expression.to_owned(),
+                    name: token.to_owned(),
+                })
+            } else {
+                Err(ParsingError::InvalidNumber {
+                    expression: expression.to_owned(),
+                    number: token.to_owned(),
+                })
+            }
+        }
+
+        // parses a step base (`*`, a single number `m`, or a range `a-b`) into the inclusive
+        // `(start, end)` bounds it spans ; shared by the plain range branch and the step branch
+        // below, since `a-b/n` reuses exactly the same base forms as `a-b`
+        //
+        // `MIN..=MAX` is the field's valid domain, e.g. `1..=12` for months or `0..=23` for
+        // hours : using an inclusive bound on both ends (rather than an exclusive `N`) is what
+        // lets 1-indexed fields express their own maximum value (December, day 31, ...).
+        #[inline(always)]
+        fn parse_range<const MIN: usize, const MAX: usize>(expression: &str, token: &str) -> Result<(usize, usize), ParsingError> {
+            // `*` spans the whole domain
+            if token == "*" {
+                return Ok((MIN, MAX));
+            }
+
+            // an explicit range `a-b`
+            if token.contains('-') {
+                let bounds: Vec<&str> = token.split('-').collect();
+
+                // if there are not 2 components, the range is invalid
+                if bounds.len() != 2 {
+                    return Err(ParsingError::InvalidRange {
+                        expression: expression.to_owned(),
+                        range: token.to_owned(),
+                    });
+                }
+
+                let start = parse_index::<MIN, MAX>(expression, bounds[0])?;
+                let end = parse_index::<MIN, MAX>(expression, bounds[1])?;
+
+                // if the start is greater than the end, the range is invalid
+                // if either bound falls outside the field's domain, the range is invalid
+                if (start > end) || (end > MAX) || (start < MIN) {
+                    return Err(ParsingError::InvalidRange {
+                        expression: expression.to_owned(),
+                        range: token.to_owned(),
+                    });
+                }
+
+                return Ok((start, end));
+            }
+
+            // a single number `m`, meaning `m..=MAX`
+            let start = parse_index::<MIN, MAX>(expression, token)?;
+            if start > MAX || start < MIN {
+                return Err(ParsingError::InvalidNumber {
+                    expression: expression.to_owned(),
+                    number: token.to_owned(),
+                });
+            }
+            Ok((start, MAX))
+        }
+
         // define an inline function that converts a string to a bitfield
         #[inline(always)]
-        fn parse_component<const N: usize>(expression: &str, component: &str) -> Result<u64, ParsingError> {
+        fn parse_component<const MIN: usize, const MAX: usize>(expression: &str, component: &str) -> Result<u64, ParsingError> {
             // if the component is a wildcard, return a bitfield with bits set to 1
             let mut bitfield = 0;
 
@@ -87,38 +281,35 @@ impl Schedule {
                     });
                 }
 
-                // if wildcard, set all bits to 1, and return (no need to continue)
+                // if wildcard, set all bits in the domain to 1, and return (no need to continue)
                 if sub_component == "*" {
-                    for i in 0..N {
+                    for i in MIN..=MAX {
                         bitfield |= 1 << i;
                     }
                     break;
                 }
 
-                // if the component is a range, set the bits in the range to 1
-                else if sub_component.contains("-") {
-                    // split the range into 2 components : the start and the end
-                    let range: Vec<&str> = sub_component.split("-").collect();
+                // if the component is selecting using a step (`*/n`, `m/n`, or `a-b/n`) ; this
+                // must be checked before the plain range branch below, since a step's base can
+                // itself be a range (`a-b/n`) and would otherwise be misparsed as one
+                else if sub_component.contains("/") {
+                    // split the step expression into 2 components : the base and the step
+                    let range: Vec<&str> = sub_component.split("/").collect();
 
-                    // if there are not 2 components, the range is invalid
+                    // if there are not 2 components, the step expression is invalid
                     if range.len() != 2 {
-                        return Err(ParsingError::InvalidRange {
+                        return Err(ParsingError::InvalidModulo {
                             expression: expression.to_owned(),
-                            range: sub_component.to_owned(),
+                            modulo: sub_component.to_owned(),
                         });
                     }
 
-                    // parse the start and end of the range
-                    let start: usize = match range[0].parse() {
-                        Ok(n) => n,
-                        Err(_) => {
-                            return Err(ParsingError::InvalidNumber {
-                                expression: expression.to_owned(),
-                                number: range[0].to_owned(),
-                            });
-                        }
-                    };
-                    let end: usize = match range[1].parse() {
+                    // the base may be `*` (the whole domain), a single number `m` (meaning
+                    // `m..=MAX`), or a range `a-b`
+                    let (range_start, range_end) = parse_range::<MIN, MAX>(expression, range[0])?;
+
+                    // parse the step
+                    let step: usize = match range[1].parse() {
                         Ok(n) => n,
                         Err(_) => {
                             return Err(ParsingError::InvalidNumber {
@@ -128,76 +319,37 @@ impl Schedule {
                         }
                     };
 
-                    // if the start is greater than the end, the range is invalid
-                    // if the end is greater than the maximum value, the range is invalid
-                    if (start > end) || (end >= N) {
-                        return Err(ParsingError::InvalidRange {
-                            expression: expression.to_owned(),
-                            range: sub_component.to_owned(),
-                        });
-                    }
-
-                    // if the range is valid, set the bits in the range to 1
-                    for i in start..=end {
-                        bitfield |= 1 << i;
-                    }
-                }
-                
-                // if the component is selecting using modulo
-                else if sub_component.contains("/") {
-                    // split the modulo into 2 components : the start and the end
-                    let range: Vec<&str> = sub_component.split("/").collect();
-
-                    // if there are not 2 components, the modulo is invalid
-                    if range.len() != 2 {
+                    // a step of 0 would never advance, which is invalid
+                    if step == 0 {
                         return Err(ParsingError::InvalidModulo {
                             expression: expression.to_owned(),
                             modulo: sub_component.to_owned(),
                         });
                     }
 
-                    // if the start if not a wildcard, the modulo is invalid
-                    if range[0] != "*" {
-                        return Err(ParsingError::InvalidModulo {
-                            expression: expression.to_owned(),
-                            modulo: sub_component.to_owned(),
-                        });
+                    // set every value of the base range that's `step` apart from its start
+                    for i in range_start..=range_end {
+                        if (i - range_start) % step == 0 {
+                            bitfield |= 1 << i;
+                        }
                     }
+                }
 
-                    // parse the modulo
-                    let modulo: usize = match range[1].parse() {
-                        Ok(n) => n,
-                        Err(_) => {
-                            return Err(ParsingError::InvalidNumber {
-                                expression: expression.to_owned(),
-                                number: range[1].to_owned(),
-                            });
-                        }
-                    };
+                // if the component is a plain range, set the bits in the range to 1
+                else if sub_component.contains("-") {
+                    let (start, end) = parse_range::<MIN, MAX>(expression, sub_component)?;
 
-                    // if the modulo is correct, set the bits in the range to 1
-                    for i in 0..N {
-                        if i % modulo == 0 {
-                            bitfield |= 1 << i;
-                        }
+                    for i in start..=end {
+                        bitfield |= 1 << i;
                     }
                 }
-                
-                // if the component is a single number
+
+                // if the component is a single number (or a month/week-day name)
                 else {
-                    // try parsing the number
-                    let index: usize = match sub_component.parse() {
-                        Ok(n) => n,
-                        Err(_) => {
-                            return Err(ParsingError::InvalidNumber {
-                                expression: expression.to_owned(),
-                                number: sub_component.to_owned(),
-                            });
-                        }
-                    };
+                    let index = parse_index::<MIN, MAX>(expression, sub_component)?;
 
-                    // if the number is greater than the maximum value, the number is invalid
-                    if index >= N {
+                    // if the number falls outside the field's domain, the number is invalid
+                    if index > MAX || index < MIN {
                         return Err(ParsingError::InvalidNumber {
                             expression: expression.to_owned(),
                             number: sub_component.to_owned(),
@@ -213,21 +365,161 @@ impl Schedule {
             Ok(bitfield)
         }
 
-        let months = parse_component::<12>(expression, component[3])?;
-        let days_of_month = parse_component::<31>(expression, component[2])?;
-        let hours = parse_component::<24>(expression, component[1])?;
-        let minutes = parse_component::<60>(expression, component[0])?;
+        let months = parse_component::<1, 12>(expression, component[3])?;
+        let days_of_month = parse_component::<1, 31>(expression, component[2])?;
+        let days_of_week = parse_component::<0, 6>(expression, component[4])?;
+        let hours = parse_component::<0, 23>(expression, component[1])?;
+        let minutes = parse_component::<0, 59>(expression, component[0])?;
+
+        // remember whether the day fields were wildcards : this drives the OR/AND rule used to
+        // combine them in `is_day_valid`
+        let days_of_month_is_wildcard = component[2] == "*";
+        let days_of_week_is_wildcard = component[4] == "*";
 
         Ok(Self {
             months,
             days_of_month,
+            days_of_week,
             hours,
             minutes,
+            days_of_month_is_wildcard,
+            days_of_week_is_wildcard,
         })
     }
 
-    pub fn get_next_match(&self, mut date: OffsetDateTime) -> OffsetDateTime {
-        date.next_minute();
+    /// create a new schedule from a small English phrase (e.g. `"every 5 minutes"`, `"daily at
+    /// 15:30"`, `"weekly on monday at 09:00"`) instead of hand-written cron syntax
+    ///
+    /// the grammar is a unit phrase — `minutely|hourly|daily|weekly|monthly|yearly`, or
+    /// `every N minutes|hours|days` — optionally followed by `at HH:MM` and/or `on <weekday>`
+    /// clauses that pin the hour/minute or day-of-week fields. `secondly` is accepted as a
+    /// keyword but always rejected, since `Schedule`'s fields have no seconds resolution to lower
+    /// it to. the phrase is lowered to the same cron fields `new` parses, so it's checked and
+    /// matched by the exact same engine.
+    ///
+    /// # Examples
+    /// ```
+    /// use scheduler::Schedule;
+    ///
+    /// let every_five_minutes = Schedule::parse_human("every 5 minutes").unwrap();
+    /// let lunchtime = Schedule::parse_human("daily at 12:30").unwrap();
+    /// let monday_morning = Schedule::parse_human("weekly on monday at 09:00").unwrap();
+    /// ```
+    pub fn parse_human(input: &str) -> Result<Self, ParsingError> {
+        // maps a `on <weekday>` token (full name or 3-letter abbreviation) to the 3-letter code
+        // `new`'s name_to_index understands
+        #[inline(always)]
+        fn weekday_alias(token: &str) -> Option<&'static str> {
+            match token.to_ascii_lowercase().as_str() {
+                "sun" | "sunday" => Some("SUN"),
+                "mon" | "monday" => Some("MON"),
+                "tue" | "tues" | "tuesday" => Some("TUE"),
+                "wed" | "wednesday" => Some("WED"),
+                "thu" | "thur" | "thurs" | "thursday" => Some("THU"),
+                "fri" | "friday" => Some("FRI"),
+                "sat" | "saturday" => Some("SAT"),
+                _ => None,
+            }
+        }
+
+        let invalid_phrase = |token: &str| ParsingError::InvalidPhrase {
+            input: input.to_owned(),
+            token: token.to_owned(),
+        };
+
+        let tokens: Vec<&str> = input.split_whitespace().collect();
+        let mut cursor = tokens.iter();
+
+        let mut minute = "*".to_owned();
+        let mut hour = "*".to_owned();
+        let mut day_of_month = "*".to_owned();
+        let mut month = "*".to_owned();
+        let mut day_of_week = "*".to_owned();
+
+        let unit = cursor.next().ok_or_else(|| invalid_phrase(input))?;
+        match unit.to_ascii_lowercase().as_str() {
+            "secondly" => return Err(invalid_phrase(unit)),
+            "minutely" => {}
+            "hourly" => minute = "0".to_owned(),
+            "daily" => {
+                minute = "0".to_owned();
+                hour = "0".to_owned();
+            }
+            "weekly" => {
+                minute = "0".to_owned();
+                hour = "0".to_owned();
+                day_of_week = "0".to_owned();
+            }
+            "monthly" => {
+                minute = "0".to_owned();
+                hour = "0".to_owned();
+                day_of_month = "1".to_owned();
+            }
+            "yearly" => {
+                minute = "0".to_owned();
+                hour = "0".to_owned();
+                day_of_month = "1".to_owned();
+                month = "1".to_owned();
+            }
+            "every" => {
+                let count_token = cursor.next().ok_or_else(|| invalid_phrase(unit))?;
+                let count: u32 = count_token
+                    .parse()
+                    .map_err(|_| invalid_phrase(count_token))?;
+                let step_unit = cursor.next().ok_or_else(|| invalid_phrase(unit))?;
+
+                match step_unit.to_ascii_lowercase().as_str() {
+                    "minute" | "minutes" | "min" | "mins" => minute = format!("*/{count}"),
+                    "hour" | "hours" | "hr" | "hrs" => {
+                        minute = "0".to_owned();
+                        hour = format!("*/{count}");
+                    }
+                    "day" | "days" => {
+                        minute = "0".to_owned();
+                        hour = "0".to_owned();
+                        day_of_month = format!("*/{count}");
+                    }
+                    _ => return Err(invalid_phrase(step_unit)),
+                }
+            }
+            _ => return Err(invalid_phrase(unit)),
+        }
+
+        // optional `at HH:MM` / `on <weekday>` clauses, in any order
+        while let Some(token) = cursor.next() {
+            match token.to_ascii_lowercase().as_str() {
+                "at" => {
+                    let time = cursor.next().ok_or_else(|| invalid_phrase(token))?;
+                    let (h, m) = time.split_once(':').ok_or_else(|| invalid_phrase(time))?;
+                    h.parse::<u8>().map_err(|_| invalid_phrase(time))?;
+                    m.parse::<u8>().map_err(|_| invalid_phrase(time))?;
+                    hour = h.to_owned();
+                    minute = m.to_owned();
+                }
+                "on" => {
+                    let weekday = cursor.next().ok_or_else(|| invalid_phrase(token))?;
+                    day_of_week = weekday_alias(weekday)
+                        .ok_or_else(|| invalid_phrase(weekday))?
+                        .to_owned();
+                }
+                _ => return Err(invalid_phrase(token)),
+            }
+        }
+
+        Self::new(&format!(
+            "{minute} {hour} {day_of_month} {month} {day_of_week}"
+        ))
+    }
+
+    /// how many times the month is allowed to advance while searching for a match before giving
+    /// up : roughly 5 years' worth of months, which covers any schedule whose matches are at most
+    /// 5 years apart, while still bounding schedules that never can match (e.g. Feb 30th). A
+    /// Feb 29th schedule searched across a skipped leap year (such as 2100) needs up to 8 years,
+    /// so that search also ends with `NoMatchWithinHorizon`
+    const MAX_MONTH_ADVANCES: u32 = 12 * 5;
+
+    pub fn get_next_match(&self, mut date: OffsetDateTime) -> Result<OffsetDateTime, TimeError> {
+        date.next_minute()?;
 
         #[inline(always)]
         fn is_month_valid(date: &mut OffsetDateTime, valid_months: u64) -> bool {
@@ -238,6 +530,31 @@ impl Schedule {
             (valid_days_of_month & (1 << date.day() as usize)) != 0
         }
         #[inline(always)]
+        fn is_day_of_week_valid(date: &mut OffsetDateTime, valid_days_of_week: u64) -> bool {
+            (valid_days_of_week & (1 << date.weekday().number_days_from_sunday() as usize)) != 0
+        }
+        // Vixie-cron OR semantics : when both the day-of-month and day-of-week fields are
+        // restricted, a date only needs to satisfy one of them ; when only one is restricted,
+        // it must satisfy that one ; when neither is restricted, every day matches.
+        #[inline(always)]
+        fn is_day_valid(
+            date: &mut OffsetDateTime,
+            valid_days_of_month: u64,
+            days_of_month_is_wildcard: bool,
+            valid_days_of_week: u64,
+            days_of_week_is_wildcard: bool,
+        ) -> bool {
+            match (days_of_month_is_wildcard, days_of_week_is_wildcard) {
+                (true, true) => true,
+                (true, false) => is_day_of_week_valid(date, valid_days_of_week),
+                (false, true) => is_day_of_month_valid(date, valid_days_of_month),
+                (false, false) => {
+                    is_day_of_month_valid(date, valid_days_of_month)
+                        || is_day_of_week_valid(date, valid_days_of_week)
+                }
+            }
+        }
+        #[inline(always)]
         fn is_hour_valid(date: &mut OffsetDateTime, valid_hours: u64) -> bool {
             (valid_hours & (1 << date.hour() as usize)) != 0
         }
@@ -246,99 +563,244 @@ impl Schedule {
             (valid_minutes & (1 << date.minute() as usize)) != 0
         }
 
-        // returns next valid month, and true if there is a loop
+        // advances to the next valid month, bumping `month_advances` once per month actually
+        // stepped (not once per call) so a schedule that can only be reached by skipping many
+        // months in a row (e.g. a single valid month) is still bounded by `MAX_MONTH_ADVANCES`
         #[inline(always)]
-        fn get_next_month(date: &mut OffsetDateTime, valid_month: u64) {
+        fn get_next_month(
+            date: &mut OffsetDateTime,
+            valid_month: u64,
+            month_advances: &mut u32,
+            max_month_advances: u32,
+        ) -> Result<(), TimeError> {
             loop {
-                date.next_month();
+                date.next_month()?;
+                *month_advances += 1;
+                if *month_advances > max_month_advances {
+                    return Err(TimeError::NoMatchWithinHorizon);
+                }
                 if is_month_valid(date, valid_month) {
-                    break;
+                    return Ok(());
                 }
             }
         }
-        // returns next valid day of month, and true if there is a loop
+        // returns next valid day (combining day-of-month and day-of-week), and true if there is a loop
         #[inline(always)]
-        fn get_next_day_of_month(date: &mut OffsetDateTime, valid_days_of_month: u64) -> bool {
+        fn get_next_day(
+            date: &mut OffsetDateTime,
+            valid_days_of_month: u64,
+            days_of_month_is_wildcard: bool,
+            valid_days_of_week: u64,
+            days_of_week_is_wildcard: bool,
+        ) -> Result<bool, TimeError> {
             let mut looped;
 
             loop {
-                looped = date.next_day();
-                if looped || is_day_of_month_valid(date, valid_days_of_month) {
+                looped = date.next_day()?;
+                if looped
+                    || is_day_valid(
+                        date,
+                        valid_days_of_month,
+                        days_of_month_is_wildcard,
+                        valid_days_of_week,
+                        days_of_week_is_wildcard,
+                    )
+                {
                     break;
                 }
             }
 
-            looped
+            Ok(looped)
         }
         #[inline(always)]
-        fn get_next_hour(date: &mut OffsetDateTime, valid_hours: u64) -> bool {
+        fn get_next_hour(date: &mut OffsetDateTime, valid_hours: u64) -> Result<bool, TimeError> {
             let mut looped;
 
             loop {
-                looped = date.next_hour();
+                looped = date.next_hour()?;
                 if looped || is_hour_valid(date, valid_hours) {
                     break;
                 }
             }
 
-            looped
+            Ok(looped)
         }
         #[inline(always)]
-        fn get_next_minute(date: &mut OffsetDateTime, valid_minutes: u64) -> bool {
+        fn get_next_minute(date: &mut OffsetDateTime, valid_minutes: u64) -> Result<bool, TimeError> {
             let mut looped;
 
             loop {
-                looped = date.next_minute();
+                looped = date.next_minute()?;
                 if looped || is_minute_valid(date, valid_minutes) {
                     break;
                 }
             }
 
-            looped
+            Ok(looped)
         }
 
+        // bounds how many times we've advanced to a new month without finding a match, so an
+        // impossible schedule (e.g. `0 0 30 2 *`) fails fast instead of looping forever
+        let mut month_advances: u32 = 0;
+
         // not very pretty, but I couldn't find a way to make the code cleaner
         // the execution is efficient though
         loop {
             if is_month_valid(&mut date, self.months) {
                 loop {
-                    if is_day_of_month_valid(&mut date, self.days_of_month) {
+                    if is_day_valid(
+                        &mut date,
+                        self.days_of_month,
+                        self.days_of_month_is_wildcard,
+                        self.days_of_week,
+                        self.days_of_week_is_wildcard,
+                    ) {
                         loop {
                             if is_hour_valid(&mut date, self.hours) {
                                 loop {
                                     if is_minute_valid(&mut date, self.minutes) {
-                                        return date;
+                                        return Ok(date);
                                     }
-                                    if get_next_minute(&mut date, self.minutes)
+                                    if get_next_minute(&mut date, self.minutes)?
                                         && !is_hour_valid(&mut date, self.hours)
                                     {
                                         break;
                                     }
                                 }
                             }
-                            if get_next_hour(&mut date, self.hours)
-                                && !is_day_of_month_valid(&mut date, self.days_of_month)
+                            if get_next_hour(&mut date, self.hours)?
+                                && !is_day_valid(
+                                    &mut date,
+                                    self.days_of_month,
+                                    self.days_of_month_is_wildcard,
+                                    self.days_of_week,
+                                    self.days_of_week_is_wildcard,
+                                )
                             {
                                 break;
                             }
                         }
                     }
-                    if get_next_day_of_month(&mut date, self.days_of_month)
-                        && !is_month_valid(&mut date, self.months)
+                    if get_next_day(
+                        &mut date,
+                        self.days_of_month,
+                        self.days_of_month_is_wildcard,
+                        self.days_of_week,
+                        self.days_of_week_is_wildcard,
+                    )? && !is_month_valid(&mut date, self.months)
                     {
                         break;
                     }
                 }
             }
-            get_next_month(&mut date, self.months);
+
+            get_next_month(
+                &mut date,
+                self.months,
+                &mut month_advances,
+                Self::MAX_MONTH_ADVANCES,
+            )?;
         }
     }
+
+    /// how many minutes `get_next_match_in` is allowed to step through while searching for a
+    /// match before giving up ; roughly 5 years' worth of minutes, mirroring the horizon used by
+    /// `get_next_match`/`MAX_MONTH_ADVANCES`
+    const MAX_MINUTE_ADVANCES: u32 = 60 * 24 * 366 * 5;
+
+    /// like `get_next_match`, but resolves wall-clock fields through `zone` at every step instead
+    /// of assuming a fixed offset.
+    ///
+    /// This is what makes matching DST-safe : on a "spring forward" gap the civil times that get
+    /// skipped are simply never visited, and on a "fall back" overlap only the earlier of the two
+    /// civil occurrences matches : the repeated one is skipped, even when the search starts
+    /// between the two. Unlike `get_next_match`, which jumps whole
+    /// days/hours/months at a time, this steps minute by minute, because the civil fields have to
+    /// be re-resolved after every step to stay correct across a transition.
+    ///
+    /// # Performance
+    /// Because of that minute-by-minute stepping, a call can walk up to `MAX_MINUTE_ADVANCES`
+    /// minutes (roughly 5 years) before giving up, re-resolving `zone`'s offset at every single
+    /// step. For an infrequent schedule (e.g. a yearly rule evaluated right after its last
+    /// occurrence) this is noticeably slower than `get_next_match`'s jump-based search. Prefer
+    /// `get_next_match` when the schedule doesn't need per-step timezone resolution, and reserve
+    /// `get_next_match_in` for schedules that actually cross DST transitions.
+    pub fn get_next_match_in<Z: TimeZone>(
+        &self,
+        date: OffsetDateTime,
+        zone: &Z,
+    ) -> Result<OffsetDateTime, TimeError> {
+        let mut utc = date.to_offset(UtcOffset::UTC);
+        utc.to_start_of_minute()?;
+        utc = utc
+            .checked_add(time::Duration::MINUTE)
+            .ok_or(TimeError::Overflow)?;
+
+        #[inline(always)]
+        fn is_day_valid(
+            date: &OffsetDateTime,
+            valid_days_of_month: u64,
+            days_of_month_is_wildcard: bool,
+            valid_days_of_week: u64,
+            days_of_week_is_wildcard: bool,
+        ) -> bool {
+            let day_of_month_match = (valid_days_of_month & (1 << date.day() as usize)) != 0;
+            let day_of_week_match = (valid_days_of_week
+                & (1 << date.weekday().number_days_from_sunday() as usize))
+                != 0;
+
+            match (days_of_month_is_wildcard, days_of_week_is_wildcard) {
+                (true, true) => true,
+                (true, false) => day_of_week_match,
+                (false, true) => day_of_month_match,
+                (false, false) => day_of_month_match || day_of_week_match,
+            }
+        }
+
+        for _ in 0..Self::MAX_MINUTE_ADVANCES {
+            let local = utc.to_offset(zone.offset_at(utc));
+
+            if (self.months & (1 << local.month() as usize)) != 0
+                && is_day_valid(
+                    &local,
+                    self.days_of_month,
+                    self.days_of_month_is_wildcard,
+                    self.days_of_week,
+                    self.days_of_week_is_wildcard,
+                )
+                && (self.hours & (1 << local.hour() as usize)) != 0
+                && (self.minutes & (1 << local.minute() as usize)) != 0
+            {
+                // a civil time repeated by a "fall back" overlap only matches on its first
+                // occurrence : skip it when the same wall-clock time already existed under the
+                // offset that was in effect a day earlier
+                let previous_offset = utc
+                    .checked_sub(time::Duration::DAY)
+                    .map_or(local.offset(), |earlier| zone.offset_at(earlier));
+                let first_occurrence = time::PrimitiveDateTime::new(local.date(), local.time())
+                    .assume_offset(previous_offset);
+                let repeated = previous_offset != local.offset()
+                    && first_occurrence < utc
+                    && zone.offset_at(first_occurrence) == previous_offset;
+                if !repeated {
+                    return Ok(local);
+                }
+            }
+
+            utc = utc
+                .checked_add(time::Duration::MINUTE)
+                .ok_or(TimeError::Overflow)?;
+        }
+
+        Err(TimeError::NoMatchWithinHorizon)
+    }
 }
 
 /// Test if the expression parsing works
 #[cfg(test)]
 mod tests {
     use super::*;
+    use time::macros::datetime;
 
     #[test]
     /// Test if the expression parsing works.
@@ -364,4 +826,242 @@ mod tests {
     /// Test that checks if multipe matches are correct.
     fn expression_multi_1() {
     }
+
+    #[test]
+    /// Test the Vixie-cron OR rule : when both the day-of-month and the day-of-week fields are
+    /// restricted, the schedule should match a date that satisfies either one.
+    fn expression_weekday_or() {
+        // every 15th of the month, and every Monday
+        let schedule = Schedule::new("0 0 15 * 1").unwrap();
+
+        // 2024-03-04 is a Monday, but not the 15th : it should still match through days_of_week
+        let monday = datetime!(2024-03-04 0:00 UTC);
+        let next = schedule.get_next_match(monday - time::Duration::MINUTE).unwrap();
+        assert_eq!(next, monday);
+    }
+
+    #[test]
+    /// When only the week-day field is restricted, only it should be honored.
+    fn expression_weekday_only() {
+        // every Monday at midnight
+        let schedule = Schedule::new("0 0 * * 1").unwrap();
+
+        let sunday = datetime!(2024-03-03 0:00 UTC);
+        let monday = datetime!(2024-03-04 0:00 UTC);
+        let next = schedule.get_next_match(sunday).unwrap();
+        assert_eq!(next, monday);
+    }
+
+    #[test]
+    /// Names should be accepted wherever numbers are, case-insensitively, and resolve to the
+    /// same bit as the equivalent number.
+    fn expression_named_month_and_weekday() {
+        let named = Schedule::new("0 0 1 jan mon").unwrap();
+        let numbered = Schedule::new("0 0 1 1 1").unwrap();
+
+        let start = datetime!(2024-01-01 0:00 UTC);
+        assert_eq!(
+            named.get_next_match(start).unwrap(),
+            numbered.get_next_match(start).unwrap()
+        );
+    }
+
+    #[test]
+    /// An unrecognized name should be reported as `InvalidName`, not `InvalidNumber`.
+    fn expression_invalid_name() {
+        let error = Schedule::new("0 0 1 foo *").unwrap_err();
+        assert!(matches!(error, ParsingError::InvalidName { .. }));
+    }
+
+    #[test]
+    /// `@-nicknames` should expand to their canonical 5-field cron form.
+    fn expression_nickname() {
+        let nickname = Schedule::new("@daily").unwrap();
+        let canonical = Schedule::new("0 0 * * *").unwrap();
+
+        let start = datetime!(2024-01-01 0:00 UTC);
+        assert_eq!(
+            nickname.get_next_match(start).unwrap(),
+            canonical.get_next_match(start).unwrap()
+        );
+    }
+
+    #[test]
+    /// `a-b/n` should match every value in `[a, b]` that's `n` apart from `a`.
+    fn expression_step_over_range() {
+        // minutes 0, 5, 10, ..., 30
+        let schedule = Schedule::new("0-30/5 * * * *").unwrap();
+
+        let start = datetime!(2024-01-01 0:02 UTC);
+        assert_eq!(schedule.get_next_match(start).unwrap(), datetime!(2024-01-01 0:05 UTC));
+    }
+
+    #[test]
+    /// `m/n` (a bare number as the base) should match every value in `[m, N)` that's `n` apart
+    /// from `m`.
+    fn expression_step_from_number() {
+        // minutes 10, 25, 40, 55
+        let schedule = Schedule::new("10/15 * * * *").unwrap();
+
+        let start = datetime!(2024-01-01 0:00 UTC);
+        assert_eq!(schedule.get_next_match(start).unwrap(), datetime!(2024-01-01 0:10 UTC));
+    }
+
+    #[test]
+    /// `months` and `days_of_month` are 1-indexed domains (1..=12, 1..=31) : December and day 31
+    /// must be reachable both by number/name and through a bare `*`, and a full `1-31`/`1-12`
+    /// range must cover every value including the upper bound.
+    fn expression_december_and_day_31_are_reachable() {
+        assert!(Schedule::new("0 0 1 12 *").is_ok());
+        assert!(Schedule::new("0 0 1 dec *").is_ok());
+        assert!(Schedule::new("0 0 31 * *").is_ok());
+        assert!(Schedule::new("0 0 1-31 * *").is_ok());
+        assert!(Schedule::new("0 0 1 1-12 *").is_ok());
+
+        // a bare `*` on day-of-month must not skip the field's own maximum value : walking
+        // forward from December 30th should land on December 31st, not jump to next January.
+        let schedule = Schedule::new("0 0 * * *").unwrap();
+        let start = datetime!(2024-12-30 0:00 UTC);
+        assert_eq!(schedule.get_next_match(start).unwrap(), datetime!(2024-12-31 0:00 UTC));
+    }
+
+    #[test]
+    /// An impossible schedule (February 30th doesn't exist) must fail fast with
+    /// `NoMatchWithinHorizon` instead of looping forever.
+    fn expression_impossible_never_panics() {
+        let schedule = Schedule::new("0 0 30 2 *").unwrap();
+
+        let start = datetime!(2024-01-01 0:00 UTC);
+        assert_eq!(
+            schedule.get_next_match(start),
+            Err(TimeError::NoMatchWithinHorizon)
+        );
+    }
+
+    #[test]
+    /// `MAX_MONTH_ADVANCES` bounds the number of months actually stepped through, not the
+    /// number of `get_next_month` calls : a schedule restricted to a single, always-invalid
+    /// month/day combination (here, June 31st, with only June as a valid month) skips 11 months
+    /// per call and must still fail fast instead of needing ~10x longer to give up.
+    fn expression_impossible_single_valid_month_never_panics() {
+        let schedule = Schedule::new("0 0 31 6 *").unwrap();
+
+        let start = datetime!(2024-01-01 0:00 UTC);
+        assert_eq!(
+            schedule.get_next_match(start),
+            Err(TimeError::NoMatchWithinHorizon)
+        );
+    }
+
+    /// a toy time zone simulating a European-style spring-forward, jumping from UTC+1 to UTC+2
+    /// at 2024-03-31 01:00 UTC (so local time jumps directly from 02:00 to 03:00)
+    struct SpringForward;
+    impl TimeZone for SpringForward {
+        fn offset_at(&self, utc: OffsetDateTime) -> UtcOffset {
+            if utc < datetime!(2024-03-31 1:00 UTC) {
+                UtcOffset::from_hms(1, 0, 0).unwrap()
+            } else {
+                UtcOffset::from_hms(2, 0, 0).unwrap()
+            }
+        }
+    }
+
+    #[test]
+    /// A civil time skipped by a DST gap should never match ; the schedule should fire on the
+    /// next day instead.
+    fn expression_dst_gap_is_skipped() {
+        // every day at 2am local time
+        let schedule = Schedule::new("0 2 * * *").unwrap();
+
+        let start = datetime!(2024-03-30 12:00 UTC);
+        let next = schedule.get_next_match_in(start, &SpringForward).unwrap();
+
+        // local 2024-03-31 02:00 never existed, so the match should land on 2024-04-01 instead
+        assert_eq!(next.day(), 1);
+        assert_eq!(next.month(), time::Month::April);
+        assert_eq!(next.hour(), 2);
+    }
+
+    #[test]
+    /// With a fixed offset, `get_next_match_in` should agree with plain `get_next_match` applied
+    /// to a date already expressed in that offset.
+    fn expression_get_next_match_in_fixed_offset() {
+        let schedule = Schedule::new("0 2 * * *").unwrap();
+        let offset = UtcOffset::from_hms(1, 0, 0).unwrap();
+
+        let start = datetime!(2024-01-01 0:00 UTC).to_offset(offset);
+        assert_eq!(
+            schedule.get_next_match_in(start, &offset).unwrap(),
+            schedule.get_next_match(start).unwrap()
+        );
+    }
+
+    #[test]
+    /// `every N minutes` should lower to a `*/N` minute field.
+    fn parse_human_every_n_minutes() {
+        let human = Schedule::parse_human("every 5 minutes").unwrap();
+        let canonical = Schedule::new("*/5 * * * *").unwrap();
+
+        let start = datetime!(2024-01-01 0:02 UTC);
+        assert_eq!(
+            human.get_next_match(start).unwrap(),
+            canonical.get_next_match(start).unwrap()
+        );
+    }
+
+    #[test]
+    /// `daily at HH:MM` should pin the hour and minute fields.
+    fn parse_human_daily_at() {
+        let human = Schedule::parse_human("daily at 15:30").unwrap();
+        let canonical = Schedule::new("30 15 * * *").unwrap();
+
+        let start = datetime!(2024-01-01 0:00 UTC);
+        assert_eq!(
+            human.get_next_match(start).unwrap(),
+            canonical.get_next_match(start).unwrap()
+        );
+    }
+
+    #[test]
+    /// `weekly on <weekday>` should pin the day-of-week field.
+    fn parse_human_weekly_on_weekday() {
+        let human = Schedule::parse_human("weekly on monday").unwrap();
+        let canonical = Schedule::new("0 0 * * MON").unwrap();
+
+        let start = datetime!(2024-01-01 0:00 UTC);
+        assert_eq!(
+            human.get_next_match(start).unwrap(),
+            canonical.get_next_match(start).unwrap()
+        );
+    }
+
+    #[test]
+    /// `secondly` can't be represented by a minute-resolution schedule, so it's always rejected.
+    fn parse_human_secondly_is_unsupported() {
+        let error = Schedule::parse_human("secondly").unwrap_err();
+        assert!(matches!(error, ParsingError::InvalidPhrase { .. }));
+    }
+
+    #[test]
+    /// An unrecognized phrase should be reported as `InvalidPhrase`.
+    fn parse_human_unknown_phrase() {
+        let error = Schedule::parse_human("fortnightly").unwrap_err();
+        assert!(matches!(error, ParsingError::InvalidPhrase { .. }));
+    }
+
+    /// calling code should be able to hold either a `Schedule` or a `RecurrenceRule` behind the
+    /// same `Recurring` interface and ask for "the next match" without caring which one it is.
+    fn assert_next_match<T: Recurring>(schedule: &T, after: OffsetDateTime, expected: OffsetDateTime) {
+        assert_eq!(schedule.next_match(after).unwrap(), Some(expected));
+    }
+
+    #[test]
+    fn schedule_and_recurrence_rule_share_the_recurring_trait() {
+        let cron = Schedule::new("0 0 * * *").unwrap();
+        let start = datetime!(2024-01-01 0:00 UTC);
+        assert_next_match(&cron, start, datetime!(2024-01-02 0:00 UTC));
+
+        let rrule = crate::RecurrenceRule::new("FREQ=DAILY", start).unwrap();
+        assert_next_match(&rrule, start, datetime!(2024-01-02 0:00 UTC));
+    }
 }
@@ -0,0 +1,730 @@
+use time::{util::days_in_year_month, Date, Duration, Month, OffsetDateTime, Time, Weekday};
+
+use crate::error::{ParsingError, TimeError};
+use crate::time_extension::TimeExtension;
+use crate::Recurring;
+
+/// how often a `RecurrenceRule` repeats (the iCalendar `FREQ` values this crate supports)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frequency {
+    Secondly,
+    Minutely,
+    Hourly,
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+/// A single RFC 5545 RRULE recurrence, as found in the iCalendar format (e.g.
+/// `FREQ=MONTHLY;INTERVAL=2;BYDAY=MO,WE;BYSETPOS=-1;COUNT=10`).
+///
+/// `Schedule`'s cron syntax can't express patterns like "the last weekday of the month" or
+/// "every other week" ; `RecurrenceRule` covers those by expanding candidate instants period by
+/// period instead of matching a fixed bitfield. It needs a `DTSTART` anchor (the iCalendar
+/// property that always accompanies an RRULE in practice) to count `INTERVAL` periods and
+/// `COUNT` occurrences from.
+///
+/// This implementation covers `FREQ`, `INTERVAL`, `COUNT`, `UNTIL`, and the `BYMONTH` /
+/// `BYMONTHDAY` / `BYDAY` / `BYHOUR` / `BYMINUTE` / `BYSETPOS` filters. It does not support
+/// `BYWEEKNO`, `BYYEARDAY`, `BYSECOND`, `WKST`, or per-`BYDAY` ordinals (`1MO`) ; a `BYDAY` entry
+/// carrying an ordinal is a parse error rather than being silently reduced to the plain weekday,
+/// and `BYSETPOS` is the supported way to express "the Nth matching day of the period".
+#[derive(Debug, Clone)]
+pub struct RecurrenceRule {
+    dtstart: OffsetDateTime,
+    freq: Frequency,
+    interval: u32,
+    count: Option<u32>,
+    until: Option<OffsetDateTime>,
+    by_month: Option<u64>,         // bit `n` set for month `n` (1-12)
+    by_month_day: Option<Vec<i32>>, // 1-31, or negative to count from the end of the month
+    by_day: Option<Vec<Weekday>>,
+    by_hour: Option<u64>,   // bit `n` set for hour `n` (0-23)
+    by_minute: Option<u64>, // bit `n` set for minute `n` (0-59)
+    by_set_pos: Option<Vec<i32>>,
+}
+
+impl RecurrenceRule {
+    /// how many periods in a row without an occurrence `RecurrenceIter` will step through, in
+    /// the absence of `COUNT`/`UNTIL`, before giving up on a rule that can never produce another occurrence (e.g. `FREQ=YEARLY;
+    /// BYMONTH=2;BYMONTHDAY=30`, which asks for February 30th every year)
+    const MAX_PERIOD_ADVANCES: u32 = 10_000;
+
+    /// parse an RRULE string (e.g. `FREQ=MONTHLY;INTERVAL=2;BYDAY=MO,WE;BYSETPOS=-1;COUNT=10`)
+    /// anchored at `dtstart`, the iCalendar `DTSTART` the RRULE is always paired with
+    pub fn new(rrule: &str, dtstart: OffsetDateTime) -> Result<Self, ParsingError> {
+        let mut freq = None;
+        let mut interval = 1;
+        let mut count = None;
+        let mut until = None;
+        let mut by_month = None;
+        let mut by_month_day = None;
+        let mut by_day = None;
+        let mut by_hour = None;
+        let mut by_minute = None;
+        let mut by_set_pos = None;
+
+        for pair in rrule.split(';') {
+            if pair.is_empty() {
+                continue;
+            }
+
+            let (key, value) = pair.split_once('=').ok_or_else(|| ParsingError::InvalidList {
+                expression: rrule.to_owned(),
+                list: pair.to_owned(),
+            })?;
+
+            match key.to_ascii_uppercase().as_str() {
+                "FREQ" => freq = Some(parse_frequency(rrule, value)?),
+                "INTERVAL" => {
+                    interval = parse_number(rrule, value)?;
+
+                    // an interval of 0 would never advance the period, which is invalid
+                    if interval == 0 {
+                        return Err(ParsingError::InvalidModulo {
+                            expression: rrule.to_owned(),
+                            modulo: value.to_owned(),
+                        });
+                    }
+                }
+                "COUNT" => count = Some(parse_number(rrule, value)?),
+                "UNTIL" => until = Some(parse_until(rrule, value)?),
+                "BYMONTH" => by_month = Some(parse_bitfield(rrule, value, 1, 12)?),
+                "BYMONTHDAY" => by_month_day = Some(parse_signed_list(rrule, value, Some(31))?),
+                "BYDAY" => by_day = Some(parse_weekday_list(rrule, value)?),
+                "BYHOUR" => by_hour = Some(parse_bitfield(rrule, value, 0, 23)?),
+                "BYMINUTE" => by_minute = Some(parse_bitfield(rrule, value, 0, 59)?),
+                "BYSETPOS" => by_set_pos = Some(parse_signed_list(rrule, value, None)?),
+
+                // unsupported keywords are reported rather than silently ignored
+                _ => {
+                    return Err(ParsingError::InvalidName {
+                        expression: rrule.to_owned(),
+                        name: key.to_owned(),
+                    })
+                }
+            }
+        }
+
+        let freq = freq.ok_or_else(|| ParsingError::MissingField {
+            expression: rrule.to_owned(),
+            field: "FREQ".to_owned(),
+        })?;
+
+        // RFC 5545 falls back to DTSTART's own position within the period when a frequency's
+        // natural `BYxxx` filter is left unset, e.g. a bare `FREQ=WEEKLY` repeats on DTSTART's
+        // weekday, and a bare `FREQ=YEARLY` repeats on DTSTART's month and day
+        match freq {
+            Frequency::Weekly if by_day.is_none() => {
+                by_day = Some(vec![dtstart.weekday()]);
+            }
+            Frequency::Monthly if by_month_day.is_none() && by_day.is_none() => {
+                by_month_day = Some(vec![dtstart.day() as i32]);
+            }
+            Frequency::Yearly => {
+                if by_month.is_none() {
+                    by_month = Some(1 << dtstart.month() as usize);
+                }
+                if by_month_day.is_none() && by_day.is_none() {
+                    by_month_day = Some(vec![dtstart.day() as i32]);
+                }
+            }
+            _ => {}
+        }
+
+        Ok(Self {
+            dtstart,
+            freq,
+            interval,
+            count,
+            until,
+            by_month,
+            by_month_day,
+            by_day,
+            by_hour,
+            by_minute,
+            by_set_pos,
+        })
+    }
+
+    /// an iterator over every occurrence of this rule, in order, starting from `DTSTART` ;
+    /// mirrors `Schedule::get_next_match`'s role for cron expressions
+    pub fn occurrences(&self) -> RecurrenceIter<'_> {
+        RecurrenceIter {
+            rule: self,
+            period_start: self.dtstart,
+            pending: Vec::new().into_iter(),
+            periods_advanced: 0,
+            emitted: 0,
+            advance_error: None,
+            exhausted: false,
+        }
+    }
+
+    /// the first occurrence strictly after `after`, matching `Schedule::get_next_match`'s API
+    pub fn next_occurrence(
+        &self,
+        after: OffsetDateTime,
+    ) -> Result<Option<OffsetDateTime>, TimeError> {
+        for occurrence in self.occurrences() {
+            let occurrence = occurrence?;
+            if occurrence > after {
+                return Ok(Some(occurrence));
+            }
+        }
+        Ok(None)
+    }
+
+    /// the `[first, last]` calendar days to scan for candidates within the period starting at
+    /// `period_start` ; `Hourly`/`Minutely`/`Secondly` don't use day-scanning (see
+    /// `candidates_in_period`), so the single-day bounds returned for them are never used
+    fn period_bounds(&self, period_start: OffsetDateTime) -> (Date, Date) {
+        match self.freq {
+            Frequency::Yearly => {
+                let year = period_start.year();
+                (
+                    Date::from_calendar_date(year, Month::January, 1).unwrap(),
+                    Date::from_calendar_date(year, Month::December, 31).unwrap(),
+                )
+            }
+            Frequency::Monthly => {
+                let year = period_start.year();
+                let month = period_start.month();
+                let first = Date::from_calendar_date(year, month, 1).unwrap();
+                let last = first
+                    .replace_day(days_in_year_month(year, month))
+                    .unwrap();
+                (first, last)
+            }
+            Frequency::Weekly => {
+                let first = period_start.date();
+                (first, first + Duration::days(6))
+            }
+            Frequency::Daily | Frequency::Hourly | Frequency::Minutely | Frequency::Secondly => {
+                let day = period_start.date();
+                (day, day)
+            }
+        }
+    }
+
+    /// whether `day` is allowed by `by_month`/`by_month_day`/`by_day` (fields left unset always
+    /// pass)
+    fn day_matches_filters(&self, day: Date) -> bool {
+        if let Some(by_month) = self.by_month {
+            if (by_month & (1 << day.month() as usize)) == 0 {
+                return false;
+            }
+        }
+
+        if let Some(by_month_day) = &self.by_month_day {
+            let days_in_month = days_in_year_month(day.year(), day.month()) as i32;
+            let matches = by_month_day.iter().any(|&n| {
+                let resolved = if n > 0 { n } else { days_in_month + n + 1 };
+                resolved == day.day() as i32
+            });
+            if !matches {
+                return false;
+            }
+        }
+
+        if let Some(by_day) = &self.by_day {
+            if !by_day.contains(&day.weekday()) {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// the times of day to pair with a matching calendar day : every `by_hour`/`by_minute`
+    /// combination if either is set, otherwise just `DTSTART`'s time of day
+    fn times_for_day(&self) -> Vec<Time> {
+        if self.by_hour.is_none() && self.by_minute.is_none() {
+            return vec![self.dtstart.time()];
+        }
+
+        let hours: Vec<u8> = match self.by_hour {
+            Some(bitfield) => (0..24).filter(|h| (bitfield & (1 << h)) != 0).collect(),
+            None => vec![self.dtstart.hour()],
+        };
+        let minutes: Vec<u8> = match self.by_minute {
+            Some(bitfield) => (0..60).filter(|m| (bitfield & (1 << m)) != 0).collect(),
+            None => vec![self.dtstart.minute()],
+        };
+
+        let mut times = Vec::with_capacity(hours.len() * minutes.len());
+        for &hour in &hours {
+            for &minute in &minutes {
+                // hour/minute both come from a validated 0-23/0-59 range, and DTSTART's own
+                // second is always valid, so this can't fail
+                times.push(Time::from_hms(hour, minute, self.dtstart.second()).unwrap());
+            }
+        }
+        times
+    }
+
+    /// selects the `BYSETPOS` positions (1-based from the front, negative from the back) out of
+    /// a period's sorted candidates ; with no `BYSETPOS`, every candidate is kept
+    fn apply_by_set_pos(&self, candidates: Vec<OffsetDateTime>) -> Vec<OffsetDateTime> {
+        let Some(positions) = &self.by_set_pos else {
+            return candidates;
+        };
+
+        let len = candidates.len() as i32;
+        let mut selected: Vec<OffsetDateTime> = positions
+            .iter()
+            .filter_map(|&pos| {
+                let index = if pos > 0 { pos - 1 } else { len + pos };
+                (index >= 0 && index < len).then(|| candidates[index as usize])
+            })
+            .collect();
+
+        selected.sort();
+        selected.dedup();
+        selected
+    }
+
+    /// whether a single instant (used for `Hourly`/`Minutely`/`Secondly`, which don't scan a
+    /// range of days) is allowed by every `BYxxx` filter that's set
+    fn instant_matches_filters(&self, date: OffsetDateTime) -> bool {
+        if !self.day_matches_filters(date.date()) {
+            return false;
+        }
+        if let Some(by_hour) = self.by_hour {
+            if (by_hour & (1 << date.hour() as usize)) == 0 {
+                return false;
+            }
+        }
+        if let Some(by_minute) = self.by_minute {
+            if (by_minute & (1 << date.minute() as usize)) == 0 {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// generates every candidate occurrence within the period starting at `period_start`, sorted
+    /// and with `BYSETPOS` applied
+    fn candidates_in_period(
+        &self,
+        period_start: OffsetDateTime,
+    ) -> Result<Vec<OffsetDateTime>, TimeError> {
+        if matches!(
+            self.freq,
+            Frequency::Hourly | Frequency::Minutely | Frequency::Secondly
+        ) {
+            // `next_period_start` truncates to the top of the hour/minute, so DTSTART's own
+            // minute/second is carried over to keep each occurrence at the same offset within
+            // its period
+            let mut instant = period_start;
+            if self.freq == Frequency::Hourly {
+                instant = instant
+                    .replace_minute(self.dtstart.minute())
+                    .map_err(|_| TimeError::Overflow)?;
+            }
+            if self.freq != Frequency::Secondly {
+                instant = instant
+                    .replace_second(self.dtstart.second())
+                    .map_err(|_| TimeError::Overflow)?;
+            }
+            return Ok(if self.instant_matches_filters(instant) {
+                vec![instant]
+            } else {
+                Vec::new()
+            });
+        }
+
+        let (first_day, last_day) = self.period_bounds(period_start);
+        let offset = self.dtstart.offset();
+
+        let mut candidates = Vec::new();
+        let mut day = first_day;
+        loop {
+            if self.day_matches_filters(day) {
+                for time in self.times_for_day() {
+                    candidates.push(day.with_time(time).assume_offset(offset));
+                }
+            }
+
+            if day == last_day {
+                break;
+            }
+            day += Duration::days(1);
+        }
+
+        candidates.sort();
+        Ok(self.apply_by_set_pos(candidates))
+    }
+
+    /// advances `period_start` by `interval` units of `freq`, reusing the same stepping helpers
+    /// `Schedule` uses
+    fn next_period_start(&self, mut date: OffsetDateTime) -> Result<OffsetDateTime, TimeError> {
+        match self.freq {
+            Frequency::Yearly => {
+                let months = self.interval.checked_mul(12).ok_or(TimeError::Overflow)?;
+                for _ in 0..months {
+                    date.next_month()?;
+                }
+            }
+            Frequency::Monthly => {
+                for _ in 0..self.interval {
+                    date.next_month()?;
+                }
+            }
+            Frequency::Weekly => {
+                let days = self.interval.checked_mul(7).ok_or(TimeError::Overflow)?;
+                for _ in 0..days {
+                    date.next_day()?;
+                }
+            }
+            Frequency::Daily => {
+                for _ in 0..self.interval {
+                    date.next_day()?;
+                }
+            }
+            Frequency::Hourly => {
+                for _ in 0..self.interval {
+                    date.next_hour()?;
+                }
+            }
+            Frequency::Minutely => {
+                for _ in 0..self.interval {
+                    date.next_minute()?;
+                }
+            }
+            Frequency::Secondly => {
+                date = date
+                    .checked_add(Duration::seconds(self.interval as i64))
+                    .ok_or(TimeError::Overflow)?;
+            }
+        }
+        Ok(date)
+    }
+}
+
+impl Recurring for RecurrenceRule {
+    fn next_match(&self, after: OffsetDateTime) -> Result<Option<OffsetDateTime>, TimeError> {
+        self.next_occurrence(after)
+    }
+}
+
+/// lazily expands a `RecurrenceRule` into its occurrences, in order
+pub struct RecurrenceIter<'a> {
+    rule: &'a RecurrenceRule,
+    period_start: OffsetDateTime,
+    pending: std::vec::IntoIter<OffsetDateTime>,
+    periods_advanced: u32,
+    emitted: u32,
+    advance_error: Option<TimeError>,
+    exhausted: bool,
+}
+
+impl Iterator for RecurrenceIter<'_> {
+    type Item = Result<OffsetDateTime, TimeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.exhausted {
+            return None;
+        }
+
+        loop {
+            if let Some(candidate) = self.pending.next() {
+                // the anchor period can contain candidates before DTSTART itself ; those never count
+                if candidate < self.rule.dtstart {
+                    continue;
+                }
+                if let Some(until) = self.rule.until {
+                    if candidate > until {
+                        self.exhausted = true;
+                        return None;
+                    }
+                }
+                if let Some(count) = self.rule.count {
+                    if self.emitted >= count {
+                        self.exhausted = true;
+                        return None;
+                    }
+                }
+                self.emitted += 1;
+                // the horizon only bounds a run of periods that yield nothing
+                self.periods_advanced = 0;
+                return Some(Ok(candidate));
+            }
+
+            if let Some(error) = self.advance_error.take() {
+                self.exhausted = true;
+                return Some(Err(error));
+            }
+
+            if self.periods_advanced >= RecurrenceRule::MAX_PERIOD_ADVANCES {
+                self.exhausted = true;
+                return Some(Err(TimeError::NoMatchWithinHorizon));
+            }
+
+            self.pending = match self.rule.candidates_in_period(self.period_start) {
+                Ok(candidates) => candidates.into_iter(),
+                Err(error) => {
+                    self.exhausted = true;
+                    return Some(Err(error));
+                }
+            };
+            self.periods_advanced += 1;
+
+            match self.rule.next_period_start(self.period_start) {
+                Ok(next_start) => self.period_start = next_start,
+                Err(error) => self.advance_error = Some(error),
+            }
+        }
+    }
+}
+
+fn parse_frequency(expression: &str, value: &str) -> Result<Frequency, ParsingError> {
+    match value.to_ascii_uppercase().as_str() {
+        "SECONDLY" => Ok(Frequency::Secondly),
+        "MINUTELY" => Ok(Frequency::Minutely),
+        "HOURLY" => Ok(Frequency::Hourly),
+        "DAILY" => Ok(Frequency::Daily),
+        "WEEKLY" => Ok(Frequency::Weekly),
+        "MONTHLY" => Ok(Frequency::Monthly),
+        "YEARLY" => Ok(Frequency::Yearly),
+        _ => Err(ParsingError::InvalidName {
+            expression: expression.to_owned(),
+            name: value.to_owned(),
+        }),
+    }
+}
+
+fn parse_number(expression: &str, value: &str) -> Result<u32, ParsingError> {
+    value.parse().map_err(|_| ParsingError::InvalidNumber {
+        expression: expression.to_owned(),
+        number: value.to_owned(),
+    })
+}
+
+/// parses a comma-separated list of signed numbers (used for `BYMONTHDAY`/`BYSETPOS`), rejecting
+/// `0` (neither field has a day/position numbered zero) and, when `max_abs` is set, any value
+/// whose magnitude exceeds it (e.g. `BYMONTHDAY` can't name a day past 31 in either direction)
+fn parse_signed_list(
+    expression: &str,
+    value: &str,
+    max_abs: Option<i32>,
+) -> Result<Vec<i32>, ParsingError> {
+    value
+        .split(',')
+        .map(|token| {
+            let n: i32 = token.parse().map_err(|_| ParsingError::InvalidNumber {
+                expression: expression.to_owned(),
+                number: token.to_owned(),
+            })?;
+
+            if n == 0 || max_abs.is_some_and(|max| n.unsigned_abs() > max as u32) {
+                return Err(ParsingError::InvalidNumber {
+                    expression: expression.to_owned(),
+                    number: token.to_owned(),
+                });
+            }
+
+            Ok(n)
+        })
+        .collect()
+}
+
+fn parse_bitfield(expression: &str, value: &str, min: usize, max: usize) -> Result<u64, ParsingError> {
+    let mut bitfield = 0;
+    for token in value.split(',') {
+        let n: usize = token.parse().map_err(|_| ParsingError::InvalidNumber {
+            expression: expression.to_owned(),
+            number: token.to_owned(),
+        })?;
+
+        if n < min || n > max {
+            return Err(ParsingError::InvalidNumber {
+                expression: expression.to_owned(),
+                number: token.to_owned(),
+            });
+        }
+
+        bitfield |= 1 << n;
+    }
+    Ok(bitfield)
+}
+
+/// a `BYDAY` entry may have a leading ordinal (`1MO`, `-1FR`), which this crate doesn't support ;
+/// rather than silently stripping it down to the plain weekday (turning "the first Monday" into
+/// "every Monday"), a token carrying one is rejected outright
+fn parse_weekday_list(expression: &str, value: &str) -> Result<Vec<Weekday>, ParsingError> {
+    value
+        .split(',')
+        .map(|token| {
+            let code = if token.len() > 2 {
+                // only a numeric prefix (`1MO`, `-1FR`) is an ordinal ; any other longer token
+                // is simply not a weekday code
+                let prefix = token.get(..token.len() - 2);
+                if prefix.is_some_and(|prefix| prefix.parse::<i32>().is_ok()) {
+                    return Err(ParsingError::UnsupportedByDayOrdinal {
+                        expression: expression.to_owned(),
+                        token: token.to_owned(),
+                    });
+                }
+                return Err(ParsingError::InvalidName {
+                    expression: expression.to_owned(),
+                    name: token.to_owned(),
+                });
+            } else {
+                token
+            };
+
+            match code.to_ascii_uppercase().as_str() {
+                "MO" => Ok(Weekday::Monday),
+                "TU" => Ok(Weekday::Tuesday),
+                "WE" => Ok(Weekday::Wednesday),
+                "TH" => Ok(Weekday::Thursday),
+                "FR" => Ok(Weekday::Friday),
+                "SA" => Ok(Weekday::Saturday),
+                "SU" => Ok(Weekday::Sunday),
+                _ => Err(ParsingError::InvalidName {
+                    expression: expression.to_owned(),
+                    name: token.to_owned(),
+                }),
+            }
+        })
+        .collect()
+}
+
+/// only the common "basic" UTC form is supported : `YYYYMMDDTHHMMSSZ`
+fn parse_until(expression: &str, value: &str) -> Result<OffsetDateTime, ParsingError> {
+    let invalid = || ParsingError::InvalidUntil {
+        expression: expression.to_owned(),
+        until: value.to_owned(),
+    };
+
+    let body = value.strip_suffix('Z').ok_or_else(invalid)?;
+    if body.len() != 15 || body.as_bytes()[8] != b'T' {
+        return Err(invalid());
+    }
+
+    let year: i32 = body[0..4].parse().map_err(|_| invalid())?;
+    let month: u8 = body[4..6].parse().map_err(|_| invalid())?;
+    let day: u8 = body[6..8].parse().map_err(|_| invalid())?;
+    let hour: u8 = body[9..11].parse().map_err(|_| invalid())?;
+    let minute: u8 = body[11..13].parse().map_err(|_| invalid())?;
+    let second: u8 = body[13..15].parse().map_err(|_| invalid())?;
+
+    let month = Month::try_from(month).map_err(|_| invalid())?;
+    let date = Date::from_calendar_date(year, month, day).map_err(|_| invalid())?;
+    let time = Time::from_hms(hour, minute, second).map_err(|_| invalid())?;
+
+    Ok(date.with_time(time).assume_utc())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+
+    #[test]
+    /// `FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1` should land on the last weekday of each
+    /// month.
+    fn last_weekday_of_the_month() {
+        let dtstart = datetime!(2024-01-01 9:00 UTC);
+        let rule = RecurrenceRule::new(
+            "FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1",
+            dtstart,
+        )
+        .unwrap();
+
+        // January 2024's last weekday is Wednesday the 31st
+        let next = rule.next_occurrence(dtstart).unwrap().unwrap();
+        assert_eq!(next, datetime!(2024-01-31 9:00 UTC));
+    }
+
+    #[test]
+    /// `INTERVAL=2` on a weekly rule should skip every other week.
+    fn every_other_week() {
+        let dtstart = datetime!(2024-01-01 9:00 UTC); // a Monday
+        let rule = RecurrenceRule::new("FREQ=WEEKLY;INTERVAL=2", dtstart).unwrap();
+
+        let first = rule.next_occurrence(dtstart).unwrap().unwrap();
+        let second = rule.next_occurrence(first).unwrap().unwrap();
+
+        assert_eq!(first, datetime!(2024-01-15 9:00 UTC));
+        assert_eq!(second, datetime!(2024-01-29 9:00 UTC));
+    }
+
+    #[test]
+    /// `COUNT` should cap the total number of occurrences, including the anchor itself.
+    fn count_limits_occurrences() {
+        let dtstart = datetime!(2024-01-01 9:00 UTC);
+        let rule = RecurrenceRule::new("FREQ=DAILY;COUNT=3", dtstart).unwrap();
+
+        let occurrences: Vec<_> = rule.occurrences().map(|o| o.unwrap()).collect();
+        assert_eq!(
+            occurrences,
+            vec![
+                datetime!(2024-01-01 9:00 UTC),
+                datetime!(2024-01-02 9:00 UTC),
+                datetime!(2024-01-03 9:00 UTC),
+            ]
+        );
+    }
+
+    #[test]
+    /// A `BYDAY` ordinal (`1MO`, the "first Monday" form) isn't supported ; it must be rejected
+    /// instead of being silently stripped down to "every Monday".
+    fn byday_ordinal_is_rejected() {
+        let dtstart = datetime!(2024-01-01 9:00 UTC);
+        assert!(matches!(
+            RecurrenceRule::new("FREQ=MONTHLY;BYDAY=1MO", dtstart),
+            Err(ParsingError::UnsupportedByDayOrdinal { .. })
+        ));
+    }
+
+    #[test]
+    /// `BYMONTHDAY`/`BYSETPOS` values that can never match anything (out of range, or zero) must
+    /// be rejected at parse time instead of silently burning through `MAX_PERIOD_ADVANCES`.
+    fn out_of_range_signed_lists_are_rejected() {
+        let dtstart = datetime!(2024-01-01 9:00 UTC);
+        assert!(RecurrenceRule::new("FREQ=DAILY;BYMONTHDAY=99", dtstart).is_err());
+        assert!(RecurrenceRule::new("FREQ=DAILY;BYMONTHDAY=0", dtstart).is_err());
+        assert!(RecurrenceRule::new("FREQ=MONTHLY;BYSETPOS=0", dtstart).is_err());
+    }
+
+    #[test]
+    /// A huge `INTERVAL` must surface `TimeError::Overflow` instead of panicking on the
+    /// `interval * 12` / `* 7` multiplication used to step whole periods.
+    fn huge_interval_overflows_gracefully() {
+        let dtstart = datetime!(2024-01-01 9:00 UTC);
+        let rule = RecurrenceRule::new("FREQ=YEARLY;INTERVAL=400000000", dtstart).unwrap();
+        assert_eq!(rule.next_occurrence(dtstart), Err(TimeError::Overflow));
+    }
+
+    #[test]
+    /// `INTERVAL=0` would never advance the period, looping forever ; it must be rejected at
+    /// parse time, the same way the cron step parser rejects a step of `0`.
+    fn zero_interval_is_rejected() {
+        let dtstart = datetime!(2024-01-01 9:00 UTC);
+        assert!(RecurrenceRule::new("FREQ=DAILY;INTERVAL=0", dtstart).is_err());
+    }
+
+    #[test]
+    /// An RRULE missing `FREQ` is invalid.
+    fn missing_freq_is_rejected() {
+        let dtstart = datetime!(2024-01-01 9:00 UTC);
+        assert!(matches!(
+            RecurrenceRule::new("INTERVAL=2", dtstart),
+            Err(ParsingError::MissingField { field, .. }) if field == "FREQ"
+        ));
+    }
+
+    #[test]
+    /// `UNTIL` with a structurally fine but invalid calendar date (February 30th) is reported as
+    /// an invalid `UNTIL`, not a generic invalid number.
+    fn invalid_until_is_reported_distinctly() {
+        let dtstart = datetime!(2024-01-01 9:00 UTC);
+        assert!(matches!(
+            RecurrenceRule::new("FREQ=DAILY;UNTIL=20240230T000000Z", dtstart),
+            Err(ParsingError::InvalidUntil { .. })
+        ));
+    }
+}